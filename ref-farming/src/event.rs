@@ -0,0 +1,66 @@
+//! Structured logging for seed lifecycle events.
+//!
+//! Emits NEP-297 compliant `EVENT_JSON:` log lines so indexers can follow
+//! staking activity without replaying full contract state.
+
+use near_sdk::env;
+use near_sdk::serde::Serialize;
+use near_sdk::serde_json;
+
+use crate::farm::FarmId;
+use crate::farm_seed::{NFTTokenId, SeedId};
+
+const STANDARD: &str = "nft_farming";
+const VERSION: &str = "1.0.0";
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SeedStakeData {
+    pub seed_id: SeedId,
+    pub seed_type: String,
+    /// amount moved by this stake/unstake
+    pub delta_amount: String,
+    /// seed's total `amount` after this change
+    pub amount: String,
+    /// set only for NFT seeds, identifying which token moved
+    pub nft_token_id: Option<NFTTokenId>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FarmAddedData {
+    pub seed_id: SeedId,
+    pub farm_id: FarmId,
+}
+
+/// One variant per seed lifecycle change we log. `data` is always a batch
+/// so several NFTs moving under one call can be reported as a single log.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum FarmingEvent {
+    SeedStake(Vec<SeedStakeData>),
+    SeedUnstake(Vec<SeedStakeData>),
+    FarmAdded(Vec<FarmAddedData>),
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+struct EventLog {
+    standard: &'static str,
+    version: &'static str,
+    #[serde(flatten)]
+    event: FarmingEvent,
+}
+
+impl FarmingEvent {
+    /// Writes this event as a single `EVENT_JSON:` log line.
+    pub fn emit(self) {
+        let log = EventLog {
+            standard: STANDARD,
+            version: VERSION,
+            event: self,
+        };
+        env::log_str(&format!("EVENT_JSON:{}", serde_json::to_string(&log).unwrap()));
+    }
+}
@@ -1,24 +1,55 @@
-//! FarmSeed stores information per seed about 
+//! FarmSeed stores information per seed about
 //! staked seed amount and farms under it.
 
-use std::collections::HashSet;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{UnorderedMap, UnorderedSet};
 use near_sdk::{Balance};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::json_types::{U128};
 use crate::errors::*;
+use crate::event::{FarmAddedData, FarmingEvent, SeedStakeData};
 use crate::farm::FarmId;
 use crate::utils::parse_seed_id;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 
-/// For MFT, SeedId composes of token_contract_id 
-/// and token's inner_id in that contract. 
+/// For MFT, SeedId composes of token_contract_id
+/// and token's inner_id in that contract.
 /// For FT, SeedId is the token_contract_id.
 pub(crate) type SeedId = String;
 
 pub(crate) type NFTTokenId = String; //paras-comic-dev.testnet@6
 
+/// Leading separator that namespaces MFT seed ids (`:{contract}@{index}`),
+/// so they can never collide with a bare FT `token_contract_id` or an NFT
+/// `{contract}@{token}` seed id, however those happen to be spelled.
+pub(crate) const MFT_SEED_SEPARATOR: char = ':';
+
+/// `reward_multiplier` is expressed in basis points of this base, so
+/// `REWARD_MULTIPLIER_BASE` itself means "no boost" (1x).
+pub const REWARD_MULTIPLIER_BASE: u64 = 10_000;
+
+/// NFT rarity weights are expressed in basis points of this base, so
+/// `WEIGHT_BASE` itself means a token contributes its full face amount.
+pub const WEIGHT_BASE: u64 = 10_000;
+
+/// Sub-prefixes appended to `seed_id` so each seed's `UnorderedSet`/
+/// `UnorderedMap` gets its own collision-free storage prefix.
+const FARMS_PREFIX: u8 = 0x00;
+const NFT_BALANCE_PREFIX: u8 = 0x01;
+
+fn farms_storage_key(seed_id: &SeedId) -> Vec<u8> {
+    let mut key = seed_id.as_bytes().to_vec();
+    key.push(FARMS_PREFIX);
+    key
+}
+
+fn nft_balance_storage_key(seed_id: &SeedId) -> Vec<u8> {
+    let mut key = seed_id.as_bytes().to_vec();
+    key.push(NFT_BALANCE_PREFIX);
+    key
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, PartialEq, Debug)]
 pub enum SeedType {
     FT,
@@ -26,6 +57,16 @@ pub enum SeedType {
     NFT
 }
 
+impl SeedType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeedType::FT => "FT",
+            SeedType::MFT => "MFT",
+            SeedType::NFT => "NFT",
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct FarmSeedMetadata {
@@ -33,18 +74,86 @@ pub struct FarmSeedMetadata {
     pub media: Option<String>,
 }
 
+/// Rarity-weight rules for an NFT seed: a default weight for any token id
+/// without an explicit override, plus per-token-id overrides for tokens
+/// that should contribute more (or less) than the default.
+#[derive(Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NFTWeightRule {
+    /// basis points of `WEIGHT_BASE`, i.e. `WEIGHT_BASE` == 1x
+    pub default_weight: u64,
+    pub token_weights: HashMap<NFTTokenId, u64>,
+}
+
+impl NFTWeightRule {
+    pub fn weight_for(&self, token_id: &NFTTokenId) -> u64 {
+        self.token_weights
+            .get(token_id)
+            .copied()
+            .unwrap_or(self.default_weight)
+    }
+}
+
+/// Single source of truth for classifying a seed id, shared by
+/// `infer_seed_type` (new seeds) and `reclassify_seed_type` (migrated
+/// seeds) so the two can never drift apart. `MFT_SEED_SEPARATOR` is the
+/// preferred way to spot an MFT seed, but seeds migrated from before
+/// that convention existed never got the leading separator; for those,
+/// fall back to the original signal (`token_id != token_index`) so
+/// they're still classified as MFT instead of silently becoming FT.
+fn classify_seed_type(seed_id: &SeedId, has_nft_balance: bool) -> SeedType {
+    let (token_id, token_index) = parse_seed_id(seed_id);
+    if has_nft_balance {
+        SeedType::NFT
+    } else if seed_id.starts_with(MFT_SEED_SEPARATOR) || token_id != token_index {
+        SeedType::MFT
+    } else {
+        SeedType::FT
+    }
+}
+
+/// Builds the canonical, unambiguous seed id for an MFT token: a leading
+/// `MFT_SEED_SEPARATOR` followed by `{token_contract_id}@{token_index}`.
+/// Entry points that mint new MFT seeds (e.g. `mft_on_transfer`) should
+/// route through this rather than hand-assembling the id — otherwise the
+/// namespacing `classify_seed_type` looks for is never actually produced,
+/// and disambiguation keeps falling back to the legacy
+/// `token_id != token_index` heuristic for every seed, old and new alike.
+pub fn mft_seed_id(token_contract_id: &str, token_index: &str) -> SeedId {
+    format!("{}{}@{}", MFT_SEED_SEPARATOR, token_contract_id, token_index)
+}
+
+fn infer_seed_type(
+    seed_id: &SeedId,
+    nft_balance: &Option<HashMap<NFTTokenId, U128>>,
+) -> SeedType {
+    classify_seed_type(seed_id, nft_balance.is_some())
+}
+
+fn new_nft_balance(
+    seed_id: &SeedId,
+    nft_balance: Option<HashMap<NFTTokenId, U128>>,
+) -> Option<UnorderedMap<NFTTokenId, U128>> {
+    nft_balance.map(|initial| {
+        let mut map = UnorderedMap::new(nft_balance_storage_key(seed_id));
+        for (token_id, balance) in initial {
+            map.insert(&token_id, &balance);
+        }
+        map
+    })
+}
+
+/// Original (v1.0.1) on-chain shape of a farm seed. Its field types must
+/// stay byte-for-byte compatible with what's already in storage, so this
+/// struct keeps the original `HashSet`/`HashMap` layout forever; only
+/// [`FarmSeedV2`] gets the `UnorderedSet`/`UnorderedMap` treatment, built
+/// fresh by `From<FarmSeed> for FarmSeedV2` at upgrade time.
 #[derive(BorshSerialize, BorshDeserialize)]
-#[cfg_attr(feature = "test", derive(Clone))]
 pub struct FarmSeed {
-    /// The Farming Token this FarmSeed represented for
     pub seed_id: SeedId,
-    /// The seed is a FT or MFT or NFT
     pub seed_type: SeedType,
-    /// all farms that accepted this seed
-    /// FarmId = {seed_id}#{next_index}
     pub farms: HashSet<FarmId>,
     pub next_index: u32,
-    /// total (staked) balance of this seed (Farming Token)
     pub amount: Balance,
     pub min_deposit: Balance,
     pub nft_balance: Option<HashMap<NFTTokenId, U128>>,
@@ -58,15 +167,7 @@ impl FarmSeed {
         nft_balance: Option<HashMap<NFTTokenId, U128>>,
         metadata: Option<FarmSeedMetadata>
     ) -> Self {
-        let (token_id, token_index) = parse_seed_id(seed_id);
-        let seed_type: SeedType;
-        if nft_balance.is_some() {
-            seed_type = SeedType::NFT;
-        } else if token_id == token_index {
-            seed_type = SeedType::FT; // If NFT, then SeedId will indicate the balance equivalent instead of adding seed with FT
-        } else {
-            seed_type = SeedType::MFT;
-        }
+        let seed_type = infer_seed_type(seed_id, &nft_balance);
         Self {
             seed_id: seed_id.clone(),
             seed_type,
@@ -78,27 +179,287 @@ impl FarmSeed {
             metadata
         }
     }
+}
+
+/// Current on-chain shape of a farm seed. Adds `reward_multiplier` and
+/// `locked_until` on top of [`FarmSeed`] to support lockup-boosted farms,
+/// and `nft_weights` for rarity-weighted NFT stakes.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct FarmSeedV2 {
+    /// The Farming Token this FarmSeed represented for
+    pub seed_id: SeedId,
+    /// The seed is a FT or MFT or NFT
+    pub seed_type: SeedType,
+    /// all farms that accepted this seed
+    /// FarmId = {seed_id}#{next_index}
+    /// kept as an `UnorderedSet` so registering a farm never has to
+    /// deserialize every other farm already under this seed
+    pub farms: UnorderedSet<FarmId>,
+    pub next_index: u32,
+    /// total (staked) balance of this seed (Farming Token)
+    pub amount: Balance,
+    pub min_deposit: Balance,
+    /// per-NFT staked balance, kept as an `UnorderedMap` so crediting or
+    /// reading a single token's balance doesn't load the whole map
+    pub nft_balance: Option<UnorderedMap<NFTTokenId, U128>>,
+    pub metadata: Option<FarmSeedMetadata>,
+    /// reward boost applied to this seed, in basis points of
+    /// `REWARD_MULTIPLIER_BASE` (i.e. `REWARD_MULTIPLIER_BASE` == 1x)
+    pub reward_multiplier: u64,
+    /// while set and in the future, this seed is under a lockup boost;
+    /// interpretation (e.g. unstake restrictions) is left to the caller
+    pub locked_until: Option<u64>,
+    /// rarity-weight rules applied to NFTs staked under this seed; `None`
+    /// means every token contributes its full face amount (weight 1x)
+    pub nft_weights: Option<NFTWeightRule>,
+}
+
+impl From<FarmSeed> for FarmSeedV2 {
+    fn from(v1: FarmSeed) -> Self {
+        let mut farms = UnorderedSet::new(farms_storage_key(&v1.seed_id));
+        for farm_id in v1.farms {
+            farms.insert(&farm_id);
+        }
+        let nft_balance = v1.nft_balance.map(|initial| {
+            let mut map = UnorderedMap::new(nft_balance_storage_key(&v1.seed_id));
+            for (token_id, balance) in initial {
+                map.insert(&token_id, &balance);
+            }
+            map
+        });
+        let mut v2 = Self {
+            seed_id: v1.seed_id,
+            seed_type: v1.seed_type,
+            farms,
+            next_index: v1.next_index,
+            amount: v1.amount,
+            min_deposit: v1.min_deposit,
+            nft_balance,
+            metadata: v1.metadata,
+            reward_multiplier: REWARD_MULTIPLIER_BASE,
+            locked_until: None,
+            nft_weights: None,
+        };
+        // `v1.seed_type` may predate `MFT_SEED_SEPARATOR` and have
+        // misclassified a legacy un-prefixed MFT seed as FT; reclassify
+        // rather than trust the stored value.
+        v2.reclassify_seed_type();
+        v2
+    }
+}
+
+impl FarmSeedV2 {
+    pub fn new(
+        seed_id: &SeedId,
+        min_deposit: Balance,
+        nft_balance: Option<HashMap<NFTTokenId, U128>>,
+        metadata: Option<FarmSeedMetadata>
+    ) -> Self {
+        let seed_type = infer_seed_type(seed_id, &nft_balance);
+        Self {
+            seed_id: seed_id.clone(),
+            seed_type,
+            farms: UnorderedSet::new(farms_storage_key(seed_id)),
+            next_index: 0,
+            amount: 0,
+            min_deposit,
+            nft_balance: new_nft_balance(seed_id, nft_balance),
+            metadata,
+            reward_multiplier: REWARD_MULTIPLIER_BASE,
+            locked_until: None,
+            nft_weights: None,
+        }
+    }
+
+    /// Configures (or clears) this NFT seed's rarity-weight rules.
+    pub fn set_nft_weights(&mut self, weights: Option<NFTWeightRule>) {
+        self.nft_weights = weights;
+    }
+
+    /// The effective weight for `token_id`, in basis points of
+    /// `WEIGHT_BASE`. Defaults to `WEIGHT_BASE` (1x) when this seed has no
+    /// weight rules configured.
+    pub fn nft_weight_for(&self, token_id: &NFTTokenId) -> u64 {
+        self.nft_weights
+            .as_ref()
+            .map(|rule| rule.weight_for(token_id))
+            .unwrap_or(WEIGHT_BASE)
+    }
+
+    /// Applies `token_id`'s rarity weight to a face `amount`, producing
+    /// the amount that actually counts toward the seed's total stake.
+    /// `amount * weight` is checked explicitly rather than relying on
+    /// `overflow-checks` to catch it: a large staked balance times a
+    /// large weight would otherwise panic with an opaque message under
+    /// `overflow-checks` (the release default), or silently wrap without
+    /// them. The final `/ WEIGHT_BASE` is an intentional floor: any
+    /// remainder below one basis point of weight is dropped.
+    fn weighted_nft_amount(&self, token_id: &NFTTokenId, amount: Balance) -> Balance {
+        let weight = self.nft_weight_for(token_id) as Balance;
+        let scaled = amount.checked_mul(weight).unwrap_or_else(|| panic!("{}", ERR500));
+        scaled / WEIGHT_BASE as Balance
+    }
+
+    /// Returns up to `limit` farm ids starting at `from_index`, without
+    /// deserializing the full farm set. Callers that need everything can
+    /// page through with successive calls.
+    pub fn get_farms(&self, from_index: u64, limit: u64) -> Vec<FarmId> {
+        self.farms
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    pub fn num_farms(&self) -> u64 {
+        self.farms.len()
+    }
+
+    /// Returns up to `limit` `(token_id, balance)` pairs starting at
+    /// `from_index`, without deserializing the whole `nft_balance` map.
+    /// Mirrors `get_farms`'s paging for the same reason.
+    pub fn get_nft_balance_page(&self, from_index: u64, limit: u64) -> Vec<(NFTTokenId, U128)> {
+        self.nft_balance
+            .as_ref()
+            .map(|map| {
+                map.iter()
+                    .skip(from_index as usize)
+                    .take(limit as usize)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Re-derives `seed_type` using the same rules as [`infer_seed_type`],
+    /// including its legacy-MFT fallback. Called from `From<FarmSeed> for
+    /// FarmSeedV2` so a seed migrated from before `MFT_SEED_SEPARATOR`
+    /// existed gets reclassified rather than keeping a stale `seed_type`.
+    pub fn reclassify_seed_type(&mut self) {
+        self.seed_type = classify_seed_type(&self.seed_id, self.nft_balance.is_some());
+    }
+
+    /// Reads a single NFT's staked balance in O(1) rather than loading
+    /// the whole `nft_balance` map.
+    pub fn get_nft_balance(&self, token_id: &NFTTokenId) -> Option<U128> {
+        self.nft_balance.as_ref().and_then(|map| map.get(token_id))
+    }
+
+    /// Builds the event payload describing a stake/unstake of `delta` on
+    /// this seed, optionally naming the NFT that moved.
+    fn stake_event_data(&self, delta: Balance, nft_token_id: Option<NFTTokenId>) -> SeedStakeData {
+        SeedStakeData {
+            seed_id: self.seed_id.clone(),
+            seed_type: self.seed_type.as_str().to_string(),
+            delta_amount: delta.to_string(),
+            amount: self.amount.to_string(),
+            nft_token_id,
+        }
+    }
+
+    /// Invariant: seed `amount` must always equal the sum over
+    /// `nft_balance` for NFT seeds; a no-op for FT/MFT seeds, which have
+    /// no `nft_balance` to compare against. Only checked in debug builds
+    /// since it walks every NFT under the seed.
+    #[cfg(debug_assertions)]
+    fn assert_nft_balance_matches_amount(&self) {
+        if let Some(nft_balance) = self.nft_balance.as_ref() {
+            let sum: Balance = nft_balance.values().map(|v| v.0).sum();
+            debug_assert_eq!(sum, self.amount, "nft_balance sum diverged from seed amount");
+        }
+    }
 
     pub fn add_amount(&mut self, amount: Balance) {
         self.amount += amount;
+        FarmingEvent::SeedStake(vec![self.stake_event_data(amount, None)]).emit();
+        #[cfg(debug_assertions)]
+        self.assert_nft_balance_matches_amount();
+    }
+
+    /// Credits `amount` (scaled by `token_id`'s rarity weight, see
+    /// [`FarmSeedV2::nft_weight_for`]) to both the seed total and the
+    /// token's individual balance. Panics if this seed has no
+    /// `nft_balance` to credit into, since that would bump `amount`
+    /// without anywhere to record which token it came from.
+    pub fn add_nft_amount(&mut self, token_id: &NFTTokenId, amount: Balance) {
+        assert!(self.nft_balance.is_some(), "{}", ERR500);
+        let weighted = self.weighted_nft_amount(token_id, amount);
+        self.amount += weighted;
+        let nft_balance = self.nft_balance.as_mut().unwrap();
+        let prev: Balance = nft_balance.get(token_id).unwrap_or(U128(0)).0;
+        nft_balance.insert(token_id, &U128(prev + weighted));
+        FarmingEvent::SeedStake(vec![self.stake_event_data(weighted, Some(token_id.clone()))]).emit();
+        #[cfg(debug_assertions)]
+        self.assert_nft_balance_matches_amount();
     }
 
     /// return seed amount remains.
     pub fn sub_amount(&mut self, amount: Balance) -> Balance {
         assert!(self.amount >= amount, "{}", ERR500);
         self.amount -= amount;
+        FarmingEvent::SeedUnstake(vec![self.stake_event_data(amount, None)]).emit();
         self.amount
     }
 
+    /// Debits `amount` directly from both the seed total and the token's
+    /// individual balance, dropping the entry once it reaches zero.
+    ///
+    /// Unlike `add_nft_amount`, `amount` here is *not* re-weighted: it
+    /// must already be expressed in the same units recorded in
+    /// `nft_balance` (see [`FarmSeedV2::get_nft_balance`]). Recomputing it
+    /// from the seed's *current* weight table via `weighted_nft_amount`
+    /// would diverge from what was actually credited if `set_nft_weights`
+    /// changed since the matching stake, silently stranding the
+    /// difference in both `nft_balance` and `amount`.
+    pub fn sub_nft_amount(&mut self, token_id: &NFTTokenId, amount: Balance) -> Balance {
+        assert!(self.amount >= amount, "{}", ERR500);
+        self.amount -= amount;
+        if let Some(nft_balance) = self.nft_balance.as_mut() {
+            let prev: Balance = nft_balance.get(token_id).unwrap_or(U128(0)).0;
+            assert!(prev >= amount, "{}", ERR500);
+            let remain = prev - amount;
+            if remain == 0 {
+                nft_balance.remove(token_id);
+            } else {
+                nft_balance.insert(token_id, &U128(remain));
+            }
+        }
+        #[cfg(debug_assertions)]
+        self.assert_nft_balance_matches_amount();
+        FarmingEvent::SeedUnstake(vec![self.stake_event_data(amount, Some(token_id.clone()))]).emit();
+        self.amount
+    }
+
+    /// Registers `farm_id` under this seed and emits a `farm_added` event.
+    /// Returns `false` if the farm was already registered.
+    pub fn add_farm(&mut self, farm_id: &FarmId) -> bool {
+        let is_new = self.farms.insert(farm_id);
+        if is_new {
+            FarmingEvent::FarmAdded(vec![FarmAddedData {
+                seed_id: self.seed_id.clone(),
+                farm_id: farm_id.clone(),
+            }]).emit();
+        }
+        is_new
+    }
+
+}
+
+/// Lets a versioned, lazily-upgraded type report whether it's behind the
+/// latest shape and migrate itself in place. Modeled after the upgrade
+/// hook in near-sdk-contract-tools, scaled down to this contract's needs.
+pub trait UpgradeHook {
+    fn need_upgrade(&self) -> bool;
+    fn upgrade_in_place(&mut self);
 }
 
 /// Versioned FarmSeed, used for lazy upgrade.
 /// Which means this structure would upgrade automatically when used.
-/// To achieve that, each time the new version comes in, 
+/// To achieve that, each time the new version comes in,
 /// each function of this enum should be carefully re-code!
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum VersionedFarmSeed {
     V101(FarmSeed),
+    V102(FarmSeedV2),
 }
 
 impl VersionedFarmSeed {
@@ -109,40 +470,57 @@ impl VersionedFarmSeed {
         nft_balance: Option<HashMap<NFTTokenId, U128>>,
         metadata: Option<FarmSeedMetadata>,
     ) -> Self {
-        VersionedFarmSeed::V101(FarmSeed::new(seed_id, min_deposit, nft_balance, metadata))
+        VersionedFarmSeed::V102(FarmSeedV2::new(seed_id, min_deposit, nft_balance, metadata))
     }
 
     /// Upgrades from other versions to the currently used version.
     pub fn upgrade(self) -> Self {
         match self {
-            VersionedFarmSeed::V101(farm_seed) => VersionedFarmSeed::V101(farm_seed),
+            VersionedFarmSeed::V101(farm_seed) => VersionedFarmSeed::V102(farm_seed.into()),
+            VersionedFarmSeed::V102(farm_seed) => VersionedFarmSeed::V102(farm_seed),
         }
     }
 
-    #[inline]
-    #[allow(unreachable_patterns)]
-    pub fn need_upgrade(&self) -> bool {
+    fn seed_id(&self) -> &SeedId {
         match self {
-            VersionedFarmSeed::V101(_) => false,
-            _ => true,
+            VersionedFarmSeed::V101(farm_seed) => &farm_seed.seed_id,
+            VersionedFarmSeed::V102(farm_seed) => &farm_seed.seed_id,
         }
     }
 
-    #[inline]
-    #[allow(unreachable_patterns)]
-    pub fn get_ref(&self) -> &FarmSeed {
+    /// Upgrades in place and returns the now-current `FarmSeedV2`, mutably.
+    /// `upgrade_in_place` only rewrites the in-memory enum, it does not
+    /// write anything to contract storage — so there is no read-only
+    /// variant of this accessor. Callers must re-persist `self` after
+    /// *every* call, even ones that only read through the result, since
+    /// a `V101` read can still trigger the upgrade that needs saving.
+    pub fn get_ref_mut(&mut self) -> &mut FarmSeedV2 {
+        self.upgrade_in_place();
         match self {
-            VersionedFarmSeed::V101(farm_seed) => farm_seed,
-            _ => unimplemented!(),
+            VersionedFarmSeed::V102(farm_seed) => farm_seed,
+            VersionedFarmSeed::V101(_) => unreachable!("upgrade_in_place always leaves V102"),
         }
     }
+}
 
-    #[inline]
-    #[allow(unreachable_patterns)]
-    pub fn get_ref_mut(&mut self) -> &mut FarmSeed {
-        match self {
-            VersionedFarmSeed::V101(farm_seed) => farm_seed,
-            _ => unimplemented!(),
+impl UpgradeHook for VersionedFarmSeed {
+    fn need_upgrade(&self) -> bool {
+        !matches!(self, VersionedFarmSeed::V102(_))
+    }
+
+    fn upgrade_in_place(&mut self) {
+        if self.need_upgrade() {
+            // `mem::replace` needs somewhere to put a `V102` while we move
+            // the real value out of `self`; reuse `self`'s own seed_id so
+            // the placeholder is thrown away without ever being persisted.
+            let placeholder = VersionedFarmSeed::V102(FarmSeedV2::new(
+                self.seed_id(),
+                0,
+                None,
+                None,
+            ));
+            let owned = std::mem::replace(self, placeholder);
+            *self = owned.upgrade();
         }
     }
 }
@@ -159,16 +537,42 @@ pub struct SeedInfo {
     pub min_deposit: U128,
     pub nft_balance: Option<HashMap<NFTTokenId, U128>>,
     pub title: Option<String>,
-    pub media: Option<String>
+    pub media: Option<String>,
+    /// effective rarity-weight table for this NFT seed, `None` if it has
+    /// no weight rules configured (every token then counts at face value)
+    pub nft_weights: Option<NFTWeightRule>,
 }
 
-impl From<&FarmSeed> for SeedInfo {
-    fn from(fs: &FarmSeed) -> Self {
-        let seed_type = match fs.seed_type {
-            SeedType::FT => "FT".to_string(),
-            SeedType::NFT => "NFT".to_string(),
-            SeedType::MFT => "MFT".to_string(),
-        };
+/// Page size used by `From<&FarmSeedV2> for SeedInfo`'s default
+/// conversion; large enough to cover the common case of a handful of
+/// farms/staked NFTs per seed, small enough that a single view call can
+/// never be forced to deserialize an unbounded amount of state. Callers
+/// with more than this many farms or distinct staked NFTs under one seed
+/// must page explicitly via [`SeedInfo::from_seed`].
+pub const DEFAULT_SEED_INFO_PAGE_LIMIT: u64 = 100;
+
+impl SeedInfo {
+    /// Builds a `SeedInfo` snapshot of `fs`, loading at most `farm_limit`
+    /// farms starting at `farm_from_index` and at most `nft_limit` NFT
+    /// balances starting at `nft_from_index` — via `get_farms`/
+    /// `get_nft_balance_page` — rather than the whole `farms`/
+    /// `nft_balance` collections. This is the paginated counterpart to
+    /// the bounded `From` conversion below; use it directly whenever a
+    /// seed could plausibly exceed `DEFAULT_SEED_INFO_PAGE_LIMIT`.
+    pub fn from_seed(
+        fs: &FarmSeedV2,
+        farm_from_index: u64,
+        farm_limit: u64,
+        nft_from_index: u64,
+        nft_limit: u64,
+    ) -> Self {
+        let seed_type = fs.seed_type.as_str().to_string();
+        let farms = fs.get_farms(farm_from_index, farm_limit);
+        let nft_balance = fs
+            .nft_balance
+            .as_ref()
+            .map(|_| fs.get_nft_balance_page(nft_from_index, nft_limit).into_iter().collect());
+        let nft_weights = fs.nft_weights.clone();
         if let Some(seed_metadata) = fs.metadata.clone() {
             Self {
                 seed_id: fs.seed_id.clone(),
@@ -176,10 +580,11 @@ impl From<&FarmSeed> for SeedInfo {
                 next_index: fs.next_index,
                 amount: fs.amount.into(),
                 min_deposit: fs.min_deposit.into(),
-                farms: fs.farms.iter().map(|key| key.clone()).collect(),
-                nft_balance: fs.nft_balance.clone(),
+                farms,
+                nft_balance,
                 title: Some(seed_metadata.title.unwrap_or("".to_string())),
-                media: Some(seed_metadata.media.unwrap_or("".to_string()))
+                media: Some(seed_metadata.media.unwrap_or("".to_string())),
+                nft_weights,
             }
         } else {
             Self {
@@ -188,11 +593,135 @@ impl From<&FarmSeed> for SeedInfo {
                 next_index: fs.next_index,
                 amount: fs.amount.into(),
                 min_deposit: fs.min_deposit.into(),
-                farms: fs.farms.iter().map(|key| key.clone()).collect(),
-                nft_balance: fs.nft_balance.clone(),
+                farms,
+                nft_balance,
                 title: Some("".to_string()),
-                media: Some("".to_string())
+                media: Some("".to_string()),
+                nft_weights,
             }
         }
     }
 }
+
+impl From<&FarmSeedV2> for SeedInfo {
+    /// Convenience conversion bounded to `DEFAULT_SEED_INFO_PAGE_LIMIT`
+    /// farms and NFT balances from the start of each collection — unlike
+    /// the previous unconditional `.iter().collect()`, this can never be
+    /// forced to load an unbounded amount of state. Callers that need to
+    /// see past the first page should call [`SeedInfo::from_seed`] and
+    /// page through explicitly.
+    fn from(fs: &FarmSeedV2) -> Self {
+        SeedInfo::from_seed(
+            fs,
+            0,
+            DEFAULT_SEED_INFO_PAGE_LIMIT,
+            0,
+            DEFAULT_SEED_INFO_PAGE_LIMIT,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v101_seed_upgrades_to_v102_on_access() {
+        // Build the genuine legacy (HashSet/HashMap) on-chain layout
+        // directly, rather than via `FarmSeed::new`, and round-trip it
+        // through real borsh (de)serialization the same way a V101 blob
+        // already in storage would be read back. `seed_id` is a legacy
+        // un-prefixed MFT id (no leading `MFT_SEED_SEPARATOR`) stored
+        // under the old (wrong) `seed_type: FT`, so the upgrade also
+        // exercises `reclassify_seed_type`'s legacy-MFT fallback.
+        let seed_id: SeedId = "paras.testnet@5".to_string();
+        let mut farms = HashSet::new();
+        farms.insert("paras.testnet@5#0".to_string());
+
+        let legacy = FarmSeed {
+            seed_id: seed_id.clone(),
+            seed_type: SeedType::FT,
+            farms,
+            next_index: 1,
+            amount: 42,
+            min_deposit: 1,
+            nft_balance: None,
+            metadata: None,
+        };
+        let bytes = borsh::to_vec(&VersionedFarmSeed::V101(legacy)).unwrap();
+        let mut versioned: VersionedFarmSeed = borsh::from_slice(&bytes).unwrap();
+        assert!(versioned.need_upgrade());
+
+        let upgraded = versioned.get_ref_mut();
+        assert_eq!(upgraded.reward_multiplier, REWARD_MULTIPLIER_BASE);
+        assert_eq!(upgraded.locked_until, None);
+        assert_eq!(upgraded.nft_weights, None);
+        assert_eq!(upgraded.num_farms(), 1);
+        assert_eq!(upgraded.seed_type, SeedType::MFT);
+        assert!(matches!(versioned, VersionedFarmSeed::V102(_)));
+        assert!(!versioned.need_upgrade());
+    }
+
+    #[test]
+    fn nft_weight_scales_staked_amount() {
+        let seed_id: SeedId = "collection.testnet@0".to_string();
+        let rare_token: NFTTokenId = "collection.testnet@1".to_string();
+        let mut nft_balance = HashMap::new();
+        nft_balance.insert(rare_token.clone(), U128(0));
+        let mut seed = FarmSeedV2::new(&seed_id, 1, Some(nft_balance), None);
+
+        let mut token_weights = HashMap::new();
+        token_weights.insert(rare_token.clone(), 2 * WEIGHT_BASE); // rare token counts double
+        seed.set_nft_weights(Some(NFTWeightRule {
+            default_weight: WEIGHT_BASE,
+            token_weights,
+        }));
+
+        seed.add_nft_amount(&rare_token, 100);
+        assert_eq!(seed.amount, 200);
+        assert_eq!(seed.get_nft_balance(&rare_token), Some(U128(200)));
+
+        // `sub_nft_amount` debits the stored (already-weighted) balance
+        // directly, so the caller passes what `get_nft_balance` reports,
+        // not the original face amount.
+        seed.sub_nft_amount(&rare_token, 200);
+        assert_eq!(seed.amount, 0);
+        assert_eq!(seed.get_nft_balance(&rare_token), None);
+    }
+
+    #[test]
+    fn sub_nft_amount_unaffected_by_later_weight_change() {
+        // Regression test: stake at one weight, change the weight, then
+        // fully unstake the exact stored balance. The debit must clear
+        // `nft_balance` to `None` rather than stranding dust because of a
+        // weight recomputed at unstake time.
+        let seed_id: SeedId = "collection.testnet@0".to_string();
+        let rare_token: NFTTokenId = "collection.testnet@1".to_string();
+        let mut nft_balance = HashMap::new();
+        nft_balance.insert(rare_token.clone(), U128(0));
+        let mut seed = FarmSeedV2::new(&seed_id, 1, Some(nft_balance), None);
+
+        let mut token_weights = HashMap::new();
+        token_weights.insert(rare_token.clone(), 2 * WEIGHT_BASE);
+        seed.set_nft_weights(Some(NFTWeightRule {
+            default_weight: WEIGHT_BASE,
+            token_weights,
+        }));
+        seed.add_nft_amount(&rare_token, 100);
+        assert_eq!(seed.amount, 200);
+
+        // Weight drops back to 1x before the stake is ever withdrawn.
+        seed.set_nft_weights(None);
+
+        let stored = seed.get_nft_balance(&rare_token).unwrap().0;
+        seed.sub_nft_amount(&rare_token, stored);
+        assert_eq!(seed.amount, 0);
+        assert_eq!(seed.get_nft_balance(&rare_token), None);
+    }
+
+    #[test]
+    fn mft_seed_id_is_classified_as_mft() {
+        let seed_id = mft_seed_id("paras.testnet", "5");
+        assert_eq!(classify_seed_type(&seed_id, false), SeedType::MFT);
+    }
+}